@@ -0,0 +1,21 @@
+use conway::game_of_life::{GameOfLife, Rules};
+use criterion::{black_box, criterion_group, criterion_main, Criterion};
+
+// Compares serial vs parallel step() on a dense 1000x1000 random grid.
+// Run `cargo bench` for the serial baseline and `cargo bench --features
+// parallel` to measure the rayon-backed path.
+fn bench_step_dense_1000x1000(c: &mut Criterion) {
+    c.bench_function("step 1000x1000", |b| {
+        b.iter_batched(
+            || GameOfLife::from_random_grid(1000, 1000, 0.3, Rules::conway()),
+            |mut game| {
+                game.step();
+                black_box(&game);
+            },
+            criterion::BatchSize::LargeInput,
+        )
+    });
+}
+
+criterion_group!(benches, bench_step_dense_1000x1000);
+criterion_main!(benches);
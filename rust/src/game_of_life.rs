@@ -1,7 +1,10 @@
-use rand::random;
-use std::collections::HashSet;
+#[cfg(feature = "parallel")]
+use rayon::prelude::*;
+use std::collections::{HashMap, HashSet, VecDeque};
+
+/// How many past generations `step()` remembers for `undo()`.
+const MAX_HISTORY: usize = 64;
 
-#[derive(Debug)]
 pub struct Cell {
     pub id: usize,
     pub x: i32,
@@ -9,6 +12,11 @@ pub struct Cell {
     pub alive: bool,
     pub next_state: bool,
     pub neighbors: HashSet<usize>,
+    // The fractal mode's nested board, if this cell has spawned one.
+    pub inner: Option<Box<GameOfLife>>,
+    // False for "floor" squares that never hold a live cell and that a
+    // `NeighborMode::LineOfSight` ray passes straight through.
+    pub occupiable: bool,
 }
 
 #[derive(Default)]
@@ -28,6 +36,16 @@ impl Cell {
             alive,
             next_state: false,
             neighbors: HashSet::new(),
+            inner: None,
+            occupiable: true,
+        }
+    }
+
+    /// A floor square: never alive, and transparent to a line-of-sight ray.
+    pub fn new_floor(id: usize, x: i32, y: i32) -> Cell {
+        Cell {
+            occupiable: false,
+            ..Cell::new(id, x, y, false)
         }
     }
 
@@ -48,16 +66,162 @@ impl Cell {
     }
 }
 
+/// An outer-totalistic rule expressed as birth/survive neighbor counts, e.g.
+/// `B3/S23` (Conway's Life), `B36/S23` (HighLife) or `B2/S` (Seeds).
+#[derive(Debug, Clone, Copy)]
+pub struct Rules {
+    pub birth: [bool; 9],
+    pub survive: [bool; 9],
+}
+
+impl Rules {
+    /// Parses a standard B/S ruleset string such as `"B3/S23"`.
+    pub fn parse(spec: &str) -> Result<Self, String> {
+        let (b_part, s_part) = spec
+            .split_once('/')
+            .ok_or_else(|| format!("ruleset '{}' is missing the B/S separator", spec))?;
+
+        let b_digits = b_part
+            .strip_prefix('B')
+            .ok_or_else(|| format!("ruleset '{}' must start with B", spec))?;
+        let s_digits = s_part
+            .strip_prefix('S')
+            .ok_or_else(|| format!("ruleset '{}' must have an S section", spec))?;
+
+        let mut birth = [false; 9];
+        let mut survive = [false; 9];
+        Self::set_digits(b_digits, &mut birth, spec)?;
+        Self::set_digits(s_digits, &mut survive, spec)?;
+
+        Ok(Rules { birth, survive })
+    }
+
+    fn set_digits(digits: &str, counts: &mut [bool; 9], spec: &str) -> Result<(), String> {
+        for ch in digits.chars() {
+            let n = ch
+                .to_digit(10)
+                .ok_or_else(|| format!("invalid neighbor count '{}' in ruleset '{}'", ch, spec))?
+                as usize;
+            if n > 8 {
+                return Err(format!(
+                    "neighbor count '{}' in ruleset '{}' is out of range (a cell has at most 8 neighbors)",
+                    ch, spec
+                ));
+            }
+            counts[n] = true;
+        }
+        Ok(())
+    }
+
+    /// Conway's original Life: B3/S23.
+    pub fn conway() -> Self {
+        Self::parse("B3/S23").expect("B3/S23 is a valid ruleset")
+    }
+}
+
+impl Default for Rules {
+    fn default() -> Self {
+        Self::conway()
+    }
+}
+
+/// How a cell's neighbors are resolved when connecting a dense grid.
+///
+/// `LineOfSight` only does something different from `Adjacent` on a grid
+/// that has non-occupiable "floor" cells for the ray to pass through, i.e.
+/// one built with `from_seating_plaintext`. A fully dense grid (as built by
+/// `from_random_grid`) has no floor squares, so every ray stops at the
+/// immediately adjacent cell and the two modes coincide.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum NeighborMode {
+    /// The 8 cells immediately surrounding a cell (standard Life).
+    Adjacent,
+    /// The first occupiable cell encountered by walking outward in each of
+    /// the 8 directions, passing through floor squares, until one is found
+    /// or the grid edge is reached, as in the Advent-of-Code "seating
+    /// system" variant.
+    LineOfSight,
+}
+
+// A full alive-state capture, taken at construction time (the seed) and
+// at the start of every step (for undo), so either backend can be restored wholesale.
+#[derive(Clone)]
+enum Snapshot {
+    Dense(Vec<bool>),
+    Sparse(HashSet<(i32, i32)>),
+}
+
+/// Side length of a fractal mode inner grid.
+pub const INNER_SIZE: usize = 4;
+
+/// Tunables for the fractal mode, where a crowded cell spawns a nested
+/// `GameOfLife` of its own. See `GameOfLife::with_fractal`.
+#[derive(Debug, Clone, Copy)]
+pub struct FractalConfig {
+    /// Spawn an inner grid once a live cell's neighbor count reaches this.
+    pub spawn_threshold: u8,
+    /// Drop an existing inner grid once the neighbor count falls below this.
+    pub despawn_threshold: u8,
+    /// Bounds how many tiers deep inner grids may nest.
+    pub max_depth: u32,
+}
+
+impl Default for FractalConfig {
+    fn default() -> Self {
+        Self {
+            spawn_threshold: 6,
+            despawn_threshold: 2,
+            max_depth: 1,
+        }
+    }
+}
+
 pub struct GameOfLife {
     width: usize,
     height: usize,
     cells: Vec<Cell>,
     next_id: usize,
+    rules: Rules,
+    neighbor_mode: NeighborMode,
+    // When set, the board is unbounded: only live coordinates are tracked,
+    // and `cells`/`width`/`height` are ignored by step()/print().
+    live_cells: Option<HashSet<(i32, i32)>>,
+    generation: usize,
+    initial_state: Snapshot,
+    history: VecDeque<Snapshot>,
+    // When set, step() recurses into/spawns nested grids per cell.
+    fractal: Option<FractalConfig>,
+    // How many tiers deep this grid itself sits (0 for a top-level grid).
+    depth: u32,
 }
 
 impl GameOfLife {
-    pub fn from_random_grid(width: usize, height: usize, density: f64) -> Self {
-        let mut game = GameOfLife::new(width, height);
+    /// Builds an unbounded board from a seed of live coordinates. Patterns
+    /// are free to grow past any original viewport, since only live cells
+    /// are ever stored.
+    pub fn from_sparse_seed(live_cells: HashSet<(i32, i32)>, rules: Rules) -> Self {
+        Self {
+            cells: Vec::new(),
+            next_id: 0,
+            width: 0,
+            height: 0,
+            rules,
+            neighbor_mode: NeighborMode::Adjacent,
+            initial_state: Snapshot::Sparse(live_cells.clone()),
+            history: VecDeque::new(),
+            generation: 0,
+            live_cells: Some(live_cells),
+            fractal: None,
+            depth: 0,
+        }
+    }
+
+    /// Builds a fully dense grid of random live cells. Every cell is
+    /// occupiable, so neighbors are always resolved `Adjacent`-style; see
+    /// `from_seating_plaintext` for a grid with floor cells that a
+    /// `NeighborMode::LineOfSight` ray can actually pass through.
+    pub fn from_random_grid(width: usize, height: usize, density: f64, rules: Rules) -> Self {
+        let mut game = GameOfLife::new(width, height, rules, NeighborMode::Adjacent);
 
         // Phase 1: Create all cells
         for y in 0..height {
@@ -68,52 +232,379 @@ impl GameOfLife {
         }
 
         // Phase 2: Connect neighbors
+        game.connect_adjacent();
+
+        game.capture_initial_state();
+        game
+    }
+
+    /// Builds a bounded grid from the plaintext pattern format (lines of
+    /// `*` for live and `.` for dead; lines starting with `!` are comments).
+    /// The grid is sized to the longest line and the line count, unless an
+    /// explicit `(width, height)` is given, in which case an overlong line
+    /// is rejected.
+    pub fn from_plaintext(
+        text: &str,
+        size: Option<(usize, usize)>,
+        rules: Rules,
+    ) -> Result<Self, String> {
+        let lines: Vec<&str> = text.lines().filter(|line| !line.starts_with('!')).collect();
+
+        let width = size
+            .map(|(width, _)| width)
+            .unwrap_or_else(|| lines.iter().map(|line| line.len()).max().unwrap_or(0));
+        let height = size.map(|(_, height)| height).unwrap_or(lines.len());
+
+        let mut game = GameOfLife::new(width, height, rules, NeighborMode::Adjacent);
+        for y in 0..height {
+            for x in 0..width {
+                game.add_cell(x as i32, y as i32, false);
+            }
+        }
+
+        for (y, line) in lines.iter().enumerate() {
+            if y >= height {
+                return Err(format!(
+                    "plaintext pattern has more rows than the declared height {}",
+                    height
+                ));
+            }
+            if line.len() > width {
+                return Err(format!(
+                    "plaintext line {} has length {} but the grid width is {}",
+                    y,
+                    line.len(),
+                    width
+                ));
+            }
+            for (x, ch) in line.chars().enumerate() {
+                match ch {
+                    '*' => game.set_alive(y * width + x, true),
+                    '.' => {}
+                    other => {
+                        return Err(format!(
+                            "unexpected character '{}' in plaintext pattern",
+                            other
+                        ))
+                    }
+                }
+            }
+        }
+
+        game.connect_adjacent();
+        game.capture_initial_state();
+        Ok(game)
+    }
+
+    /// Builds a `NeighborMode::LineOfSight` grid from the Advent-of-Code
+    /// "seating system" plaintext format: `L` an empty seat, `#` an
+    /// occupied seat, and `.` floor that a line-of-sight ray passes through
+    /// without stopping. Lines starting with `!` are comments.
+    pub fn from_seating_plaintext(text: &str, rules: Rules) -> Result<Self, String> {
+        let lines: Vec<&str> = text.lines().filter(|line| !line.starts_with('!')).collect();
+        let width = lines.iter().map(|line| line.len()).max().unwrap_or(0);
+        let height = lines.len();
+
+        let mut game = GameOfLife::new(width, height, rules, NeighborMode::LineOfSight);
+        for y in 0..height {
+            for x in 0..width {
+                game.add_floor_cell(x as i32, y as i32);
+            }
+        }
+
+        for (y, line) in lines.iter().enumerate() {
+            if line.len() > width {
+                return Err(format!(
+                    "seating line {} has length {} but the grid width is {}",
+                    y,
+                    line.len(),
+                    width
+                ));
+            }
+            for (x, ch) in line.chars().enumerate() {
+                let id = y * width + x;
+                match ch {
+                    '.' => {}
+                    'L' => game.cells[id] = Cell::new(id, x as i32, y as i32, false),
+                    '#' => game.cells[id] = Cell::new(id, x as i32, y as i32, true),
+                    other => {
+                        return Err(format!(
+                            "unexpected character '{}' in seating pattern",
+                            other
+                        ))
+                    }
+                }
+            }
+        }
+
+        game.connect_line_of_sight();
+        game.capture_initial_state();
+        Ok(game)
+    }
+
+    /// Builds a bounded grid from the RLE pattern format: an `x = W, y = H`
+    /// header followed by a run-length body where `<n>b`/`<n>o` are runs of
+    /// dead/live cells, `$` ends a row and `!` terminates the pattern.
+    pub fn from_rle(text: &str, rules: Rules) -> Result<Self, String> {
+        let mut width = 0usize;
+        let mut height = 0usize;
+        let mut body = String::new();
+
+        for line in text.lines() {
+            let line = line.trim();
+            if line.is_empty() || line.starts_with('#') {
+                continue;
+            }
+            if line.starts_with('x') {
+                for field in line.split(',') {
+                    let mut parts = field.splitn(2, '=');
+                    let key = parts.next().unwrap_or("").trim();
+                    let value = parts.next().unwrap_or("").trim();
+                    match key {
+                        "x" => {
+                            width = value
+                                .parse()
+                                .map_err(|_| format!("invalid width in RLE header: '{}'", value))?
+                        }
+                        "y" => {
+                            height = value
+                                .parse()
+                                .map_err(|_| format!("invalid height in RLE header: '{}'", value))?
+                        }
+                        _ => {}
+                    }
+                }
+                continue;
+            }
+            body.push_str(line);
+        }
+
+        if width == 0 || height == 0 {
+            return Err("RLE pattern is missing an 'x = W, y = H' header".to_string());
+        }
+
+        let mut game = GameOfLife::new(width, height, rules, NeighborMode::Adjacent);
         for y in 0..height {
             for x in 0..width {
-                let cell_id = y * width + x; // Calculate ID from coordinates
+                game.add_cell(x as i32, y as i32, false);
+            }
+        }
+
+        let mut x = 0usize;
+        let mut y = 0usize;
+        let mut run_length = String::new();
+
+        for ch in body.chars() {
+            match ch {
+                '!' => break,
+                '$' => {
+                    let n = Self::take_run(&mut run_length, '$')?;
+                    y += n;
+                    x = 0;
+                }
+                'b' | 'o' => {
+                    let n = Self::take_run(&mut run_length, ch)?;
+                    if y >= height {
+                        return Err(format!(
+                            "RLE pattern has more rows than the declared height {}",
+                            height
+                        ));
+                    }
+                    if x + n > width {
+                        return Err(format!(
+                            "RLE row {} overflows the declared width {} (run of {} starting at column {})",
+                            y, width, n, x
+                        ));
+                    }
+                    if ch == 'o' {
+                        for offset in 0..n {
+                            game.set_alive(y * width + x + offset, true);
+                        }
+                    }
+                    x += n;
+                }
+                digit if digit.is_ascii_digit() => run_length.push(digit),
+                _ => {} // ignore whitespace
+            }
+        }
+
+        game.connect_adjacent();
+        game.capture_initial_state();
+        Ok(game)
+    }
+
+    fn take_run(run_length: &mut String, tag: char) -> Result<usize, String> {
+        let n = if run_length.is_empty() {
+            1
+        } else {
+            run_length
+                .parse()
+                .map_err(|_| format!("invalid run length '{}' before '{}'", run_length, tag))?
+        };
+        run_length.clear();
+        Ok(n)
+    }
+
+    fn connect_adjacent(&mut self) {
+        for y in 0..self.height {
+            for x in 0..self.width {
+                let cell_id = y * self.width + x;
 
-                // Check all 8 directions
                 for dx in -1..=1 {
                     for dy in -1..=1 {
                         if dx == 0 && dy == 0 {
                             continue;
-                        } // Skip self
+                        }
 
                         let neighbor_x = x as i32 + dx;
                         let neighbor_y = y as i32 + dy;
 
-                        // Check bounds
                         if neighbor_x >= 0
-                            && neighbor_x < width as i32
+                            && neighbor_x < self.width as i32
                             && neighbor_y >= 0
-                            && neighbor_y < height as i32
+                            && neighbor_y < self.height as i32
                         {
-                            let neighbor_id = (neighbor_y as usize) * width + (neighbor_x as usize);
-                            game.add_neighbor_relationship(cell_id, neighbor_id);
+                            let neighbor_id =
+                                (neighbor_y as usize) * self.width + (neighbor_x as usize);
+                            self.add_neighbor_relationship(cell_id, neighbor_id);
                         }
                     }
                 }
             }
         }
+    }
 
-        game
+    fn connect_line_of_sight(&mut self) {
+        for y in 0..self.height {
+            for x in 0..self.width {
+                let cell_id = y * self.width + x;
+
+                for dx in -1..=1 {
+                    for dy in -1..=1 {
+                        if dx == 0 && dy == 0 {
+                            continue;
+                        }
+
+                        // Walk outward one step at a time, passing straight
+                        // through floor squares, until we hit an occupiable
+                        // cell or fall off the grid edge.
+                        let mut step = 1i32;
+                        loop {
+                            let neighbor_x = x as i32 + dx * step;
+                            let neighbor_y = y as i32 + dy * step;
+
+                            if neighbor_x < 0
+                                || neighbor_x >= self.width as i32
+                                || neighbor_y < 0
+                                || neighbor_y >= self.height as i32
+                            {
+                                break;
+                            }
+
+                            let neighbor_id =
+                                (neighbor_y as usize) * self.width + (neighbor_x as usize);
+                            if let Some(neighbor) = self.get_cell(neighbor_id) {
+                                if neighbor.occupiable {
+                                    self.add_neighbor_relationship(cell_id, neighbor_id);
+                                    break;
+                                }
+                            }
+
+                            step += 1;
+                        }
+                    }
+                }
+            }
+        }
     }
 
-    pub fn new(width: usize, height: usize) -> Self {
+    pub fn new(width: usize, height: usize, rules: Rules, neighbor_mode: NeighborMode) -> Self {
         Self {
             cells: Vec::new(),
             next_id: 0,
             width,
             height,
+            rules,
+            neighbor_mode,
+            live_cells: None,
+            generation: 0,
+            initial_state: Snapshot::Dense(Vec::new()),
+            history: VecDeque::new(),
+            fractal: None,
+            depth: 0,
         }
     }
 
-    pub fn get_cell(&self, id: usize) -> Option<&Cell> {
-        self.cells.get(id)
+    /// Opts this grid into the fractal mode: crowded cells spawn nested
+    /// boards of their own, recursed before the outer cell resolves.
+    pub fn with_fractal(mut self, config: FractalConfig) -> Self {
+        self.fractal = Some(config);
+        self
+    }
+
+    pub fn is_sparse(&self) -> bool {
+        self.live_cells.is_some()
+    }
+
+    pub fn generation(&self) -> usize {
+        self.generation
+    }
+
+    /// Restores every cell to its original seed state and clears the
+    /// generation counter and undo history.
+    pub fn reset(&mut self) {
+        let initial = self.initial_state.clone();
+        self.restore_snapshot(initial);
+        self.generation = 0;
+        self.history.clear();
+    }
+
+    /// Rewinds to the previous generation, returning an error if `step()`
+    /// has not been called since the board was built or last reset.
+    pub fn undo(&mut self) -> Result<(), String> {
+        let snapshot = self
+            .history
+            .pop_back()
+            .ok_or_else(|| "no prior generation to undo to".to_string())?;
+        self.restore_snapshot(snapshot);
+        self.generation = self.generation.saturating_sub(1);
+        Ok(())
+    }
+
+    fn snapshot(&self) -> Snapshot {
+        match &self.live_cells {
+            Some(live) => Snapshot::Sparse(live.clone()),
+            None => Snapshot::Dense(self.cells.iter().map(|cell| cell.alive).collect()),
+        }
+    }
+
+    fn restore_snapshot(&mut self, snapshot: Snapshot) {
+        match snapshot {
+            Snapshot::Dense(states) => {
+                // A Snapshot only records alive/dead, not fractal-mode inner
+                // grids, so there's no recorded inner state to restore to —
+                // drop whatever's there rather than leave stale nested boards
+                // from generations past the one being restored to.
+                for (cell, alive) in self.cells.iter_mut().zip(states) {
+                    cell.alive = alive;
+                    cell.next_state = alive;
+                    cell.inner = None;
+                }
+            }
+            Snapshot::Sparse(live) => self.live_cells = Some(live),
+        }
     }
 
-    fn get_all_cells(&self) -> &[Cell] {
-        &self.cells
+    fn capture_initial_state(&mut self) {
+        self.initial_state = self.snapshot();
+    }
+
+    pub fn neighbor_mode(&self) -> NeighborMode {
+        self.neighbor_mode
+    }
+
+    pub fn get_cell(&self, id: usize) -> Option<&Cell> {
+        self.cells.get(id)
     }
 
     fn add_cell(&mut self, x: i32, y: i32, alive: bool) -> usize {
@@ -126,21 +617,60 @@ impl GameOfLife {
         id
     }
 
+    fn add_floor_cell(&mut self, x: i32, y: i32) -> usize {
+        let id = self.next_id;
+        self.next_id += 1;
+
+        let cell = Cell::new_floor(id, x, y);
+        self.cells.push(cell);
+
+        id
+    }
+
+    fn set_alive(&mut self, id: usize, alive: bool) {
+        if let Some(cell) = self.cells.get_mut(id) {
+            cell.alive = alive;
+        }
+    }
+
+    fn next_state_for(&self, cell: &Cell) -> bool {
+        let alive_neighbors = cell.get_alive_neighbor_count(self).min(8);
+        if cell.alive {
+            self.rules.survive[alive_neighbors]
+        } else {
+            self.rules.birth[alive_neighbors]
+        }
+    }
+
     pub fn step(&mut self) {
-        // Phase 1: Calculate next states (read-only pass)
+        if self.history.len() == MAX_HISTORY {
+            self.history.pop_front();
+        }
+        self.history.push_back(self.snapshot());
+        self.generation += 1;
+
+        if self.live_cells.is_some() {
+            self.step_sparse();
+            return;
+        }
+
+        if let Some(fractal) = self.fractal {
+            self.step_fractal(fractal);
+        }
+
+        // Phase 1: Calculate next states (read-only pass). `get_alive_neighbor_count`
+        // only takes `&GameOfLife`, so this is sound to run concurrently.
+        #[cfg(feature = "parallel")]
+        let next_states: Vec<bool> = self
+            .cells
+            .par_iter()
+            .map(|cell| self.next_state_for(cell))
+            .collect();
+        #[cfg(not(feature = "parallel"))]
         let next_states: Vec<bool> = self
             .cells
             .iter()
-            .map(|cell| {
-                let alive_neighbors = cell.get_alive_neighbor_count(self);
-                if cell.alive {
-                    // Live cells stay alive with 2 or 3 neighbors
-                    alive_neighbors == 2 || alive_neighbors == 3
-                } else {
-                    // Dead cells become alive with exactly 3 neighbors
-                    alive_neighbors == 3
-                }
-            })
+            .map(|cell| self.next_state_for(cell))
             .collect();
 
         // Phase 2: Apply the states (mutable pass)
@@ -150,6 +680,82 @@ impl GameOfLife {
         }
     }
 
+    // Recurses into each cell's inner grid (if any) and then spawns or
+    // drops one based on how crowded the cell's own neighborhood is.
+    fn step_fractal(&mut self, config: FractalConfig) {
+        let rules = self.rules;
+        let depth = self.depth;
+
+        for i in 0..self.cells.len() {
+            let alive_neighbors = self.cells[i].get_alive_neighbor_count(self).min(8) as u8;
+
+            if let Some(inner) = self.cells[i].inner.as_mut() {
+                inner.step();
+            }
+
+            let cell = &mut self.cells[i];
+            if cell.alive
+                && cell.inner.is_none()
+                && alive_neighbors >= config.spawn_threshold
+                && depth < config.max_depth
+            {
+                let mut inner = GameOfLife::from_random_grid(INNER_SIZE, INNER_SIZE, 0.5, rules);
+                inner.depth = depth + 1;
+                if depth + 1 < config.max_depth {
+                    inner.fractal = Some(config);
+                }
+                cell.inner = Some(Box::new(inner));
+            } else if cell.inner.is_some() && alive_neighbors < config.despawn_threshold {
+                cell.inner = None;
+            }
+        }
+    }
+
+    /// Number of live cells, for either backend.
+    pub fn population(&self) -> usize {
+        match &self.live_cells {
+            Some(live) => live.len(),
+            None => self.cells.iter().filter(|cell| cell.alive).count(),
+        }
+    }
+
+    // Only scans the neighborhood of live cells instead of the whole plane,
+    // so the cost tracks population size rather than board area.
+    fn step_sparse(&mut self) {
+        let live = self
+            .live_cells
+            .as_ref()
+            .expect("step_sparse called without a sparse backend");
+
+        let mut neighbor_counts: HashMap<(i32, i32), u8> = HashMap::new();
+        for &(x, y) in live {
+            for dx in -1..=1 {
+                for dy in -1..=1 {
+                    if dx == 0 && dy == 0 {
+                        continue;
+                    }
+                    *neighbor_counts.entry((x + dx, y + dy)).or_insert(0) += 1;
+                }
+            }
+        }
+
+        let rules = self.rules;
+        let next_live: HashSet<(i32, i32)> = neighbor_counts
+            .into_iter()
+            .filter(|&(coord, count)| {
+                let count = count.min(8) as usize;
+                if live.contains(&coord) {
+                    rules.survive[count]
+                } else {
+                    rules.birth[count]
+                }
+            })
+            .map(|(coord, _)| coord)
+            .collect();
+
+        self.live_cells = Some(next_live);
+    }
+
     pub fn add_neighbor_relationship(&mut self, cell1_id: usize, cell2_id: usize) {
         // Add cell2 as neighbor of cell1
         if let Some(cell1) = self.cells.get_mut(cell1_id) {
@@ -162,16 +768,335 @@ impl GameOfLife {
     }
 
     pub fn print(&self) {
+        let Some(live) = &self.live_cells else {
+            for y in 0..self.height {
+                let mut row = String::new();
+                for x in 0..self.width {
+                    let cell_id = y * self.width + x;
+                    if let Some(cell) = self.get_cell(cell_id) {
+                        row.push_str(if cell.alive { "██" } else { "░░" });
+                    }
+                }
+                println!("{}", row);
+            }
+            println!();
+            return;
+        };
+
+        let Some(((min_x, min_y), (max_x, max_y))) = Self::bounding_box(live) else {
+            println!();
+            return;
+        };
+        self.print_viewport(min_x, min_y, max_x, max_y);
+    }
+
+    // Renders a sparse board within an explicit viewport, e.g. to keep the
+    // camera fixed while a pattern (such as a glider) drifts past it.
+    pub fn print_viewport(&self, min_x: i32, min_y: i32, max_x: i32, max_y: i32) {
+        let live = self
+            .live_cells
+            .as_ref()
+            .expect("print_viewport called without a sparse backend");
+
+        for y in min_y..=max_y {
+            let mut row = String::new();
+            for x in min_x..=max_x {
+                row.push_str(if live.contains(&(x, y)) {
+                    "██"
+                } else {
+                    "░░"
+                });
+            }
+            println!("{}", row);
+        }
+        println!();
+    }
+
+    /// Like `print()`, but a cell with an inner grid renders that grid's
+    /// population count instead of a plain live/dead glyph.
+    pub fn print_with_inner_population(&self) {
         for y in 0..self.height {
             let mut row = String::new();
             for x in 0..self.width {
                 let cell_id = y * self.width + x;
                 if let Some(cell) = self.get_cell(cell_id) {
-                    row.push_str(if cell.alive { "██" } else { "░░" });
+                    match &cell.inner {
+                        Some(inner) => row.push_str(&format!("{:>3}", inner.population())),
+                        None => row.push_str(if cell.alive { " ██" } else { " ░░" }),
+                    }
                 }
             }
             println!("{}", row);
         }
         println!();
     }
+
+    fn bounding_box(live: &HashSet<(i32, i32)>) -> Option<((i32, i32), (i32, i32))> {
+        let mut iter = live.iter();
+        let &(first_x, first_y) = iter.next()?;
+        let (mut min_x, mut max_x) = (first_x, first_x);
+        let (mut min_y, mut max_y) = (first_y, first_y);
+
+        for &(x, y) in iter {
+            min_x = min_x.min(x);
+            max_x = max_x.max(x);
+            min_y = min_y.min(y);
+            max_y = max_y.max(y);
+        }
+
+        Some(((min_x, min_y), (max_x, max_y)))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn sparse_backend_glider_survives() {
+        // The classic 5-cell glider: a period-4 oscillator that also drifts
+        // diagonally. Population should never change as it evolves.
+        let glider: HashSet<(i32, i32)> = [(1, 0), (2, 1), (0, 2), (1, 2), (2, 2)]
+            .into_iter()
+            .collect();
+        let mut game = GameOfLife::from_sparse_seed(glider, Rules::conway());
+        assert!(game.is_sparse());
+
+        for _ in 0..16 {
+            game.step();
+            assert_eq!(game.population(), 5);
+        }
+    }
+
+    #[test]
+    fn line_of_sight_skips_floor_and_differs_from_adjacent() {
+        // A ring of floor around a single occupied corner seat: in Adjacent
+        // mode the opposite corner has no live neighbors, but in
+        // LineOfSight mode it sees straight across the floor to the seat.
+        let pattern = "#..\n...\n..L\n";
+
+        let line_of_sight = GameOfLife::from_seating_plaintext(pattern, Rules::conway()).unwrap();
+        assert_eq!(
+            line_of_sight
+                .get_cell(8)
+                .unwrap()
+                .get_alive_neighbor_count(&line_of_sight),
+            1
+        );
+
+        // The same layout via the dense Adjacent-mode loader never connects
+        // the two corners, since they are more than one step apart.
+        let far_corner =
+            GameOfLife::from_plaintext("*..\n...\n...\n", None, Rules::conway()).unwrap();
+        assert_eq!(
+            far_corner
+                .get_cell(8)
+                .unwrap()
+                .get_alive_neighbor_count(&far_corner),
+            0
+        );
+    }
+
+    #[test]
+    fn from_random_grid_always_connects_adjacent() {
+        // from_random_grid has no floor cells, so every neighbor relationship
+        // is the immediately adjacent one: a fully dense 3x3 grid's center
+        // cell sees all 8 others.
+        let game = GameOfLife::from_random_grid(3, 3, 1.0, Rules::conway());
+        assert_eq!(game.get_cell(4).unwrap().neighbors.len(), 8);
+        assert_eq!(game.neighbor_mode(), NeighborMode::Adjacent);
+    }
+
+    #[test]
+    fn step_matches_the_expected_next_generation_under_either_feature_set() {
+        // A vertical blinker's next generation is deterministic: running this
+        // same assertion with `--features parallel` exercises the
+        // rayon-backed par_iter() path in step() against the exact expected
+        // output as the serial path, so the two can't silently diverge.
+        let mut game = GameOfLife::from_plaintext(
+            ".....\n..*..\n..*..\n..*..\n.....\n",
+            None,
+            Rules::conway(),
+        )
+        .unwrap();
+        game.step();
+
+        assert_eq!(game.population(), 3);
+        assert!(game.get_cell(11).unwrap().alive);
+        assert!(game.get_cell(12).unwrap().alive);
+        assert!(game.get_cell(13).unwrap().alive);
+    }
+
+    #[test]
+    fn parses_conway() {
+        let rules = Rules::parse("B3/S23").unwrap();
+        assert_eq!(
+            rules.birth,
+            [false, false, false, true, false, false, false, false, false]
+        );
+        assert_eq!(
+            rules.survive,
+            [false, false, true, true, false, false, false, false, false]
+        );
+    }
+
+    #[test]
+    fn parses_seeds_with_empty_survive_section() {
+        let rules = Rules::parse("B2/S").unwrap();
+        assert!(rules.birth[2]);
+        assert_eq!(rules.survive, [false; 9]);
+    }
+
+    #[test]
+    fn rejects_missing_separator() {
+        assert!(Rules::parse("B3S23").is_err());
+    }
+
+    #[test]
+    fn rejects_missing_b_prefix() {
+        assert!(Rules::parse("3/S23").is_err());
+    }
+
+    #[test]
+    fn rejects_missing_s_prefix() {
+        assert!(Rules::parse("B3/23").is_err());
+    }
+
+    #[test]
+    fn rejects_non_digit_neighbor_count() {
+        assert!(Rules::parse("B3/SX").is_err());
+    }
+
+    #[test]
+    fn rejects_out_of_range_neighbor_count_instead_of_panicking() {
+        // A cell has at most 8 neighbors, so '9' must be rejected rather
+        // than panic when indexing the 9-element birth/survive arrays.
+        assert!(Rules::parse("B9/S23").is_err());
+        assert!(Rules::parse("B3/S239").is_err());
+    }
+
+    #[test]
+    fn plaintext_loads_a_live_cell() {
+        let game = GameOfLife::from_plaintext("...\n.*.\n...\n", None, Rules::conway()).unwrap();
+        assert!(game.get_cell(4).unwrap().alive);
+        assert_eq!(game.population(), 1);
+    }
+
+    #[test]
+    fn plaintext_rejects_overlong_line() {
+        // An explicit size of width 2 rejects the 3-character second line.
+        let game = GameOfLife::from_plaintext("..\n...\n", Some((2, 2)), Rules::conway());
+        assert!(game.is_err());
+    }
+
+    #[test]
+    fn plaintext_rejects_more_rows_than_the_declared_height() {
+        // An explicit height of 1 must reject the second row rather than
+        // silently dropping its live cell.
+        let game = GameOfLife::from_plaintext("..\n.*\n", Some((2, 1)), Rules::conway());
+        assert!(game.is_err());
+    }
+
+    #[test]
+    fn plaintext_rejects_unknown_character() {
+        let game = GameOfLife::from_plaintext("..\nX.\n", None, Rules::conway());
+        assert!(game.is_err());
+    }
+
+    #[test]
+    fn rle_decodes_a_glider() {
+        let game = GameOfLife::from_rle("x = 3, y = 3\nbo$2bo$3o!", Rules::conway()).unwrap();
+        assert_eq!(game.population(), 5);
+        assert!(game.get_cell(1).unwrap().alive); // row 0: .O.
+        assert!(game.get_cell(5).unwrap().alive); // row 1: ..O
+        assert!(game.get_cell(6).unwrap().alive); // row 2: OOO
+        assert!(game.get_cell(7).unwrap().alive);
+        assert!(game.get_cell(8).unwrap().alive);
+    }
+
+    #[test]
+    fn rle_rejects_missing_header() {
+        assert!(GameOfLife::from_rle("bo$2bo$3o!", Rules::conway()).is_err());
+    }
+
+    #[test]
+    fn rle_rejects_a_row_that_overflows_the_declared_width() {
+        // Declares a 3-wide board but the first row's run covers 4 columns.
+        let game = GameOfLife::from_rle("x = 3, y = 3\n4o$3o$3o!", Rules::conway());
+        assert!(game.is_err());
+    }
+
+    #[test]
+    fn rle_rejects_more_rows_than_the_declared_height() {
+        let game = GameOfLife::from_rle("x = 3, y = 1\n3o$3o!", Rules::conway());
+        assert!(game.is_err());
+    }
+
+    #[test]
+    fn undo_rewinds_one_generation() {
+        let mut game =
+            GameOfLife::from_plaintext("...\n.*.\n...\n", None, Rules::conway()).unwrap();
+        game.step();
+        assert_eq!(game.generation(), 1);
+        assert_eq!(game.population(), 0); // a lone cell dies of isolation
+
+        game.undo().unwrap();
+        assert_eq!(game.generation(), 0);
+        assert!(game.get_cell(4).unwrap().alive);
+    }
+
+    #[test]
+    fn undo_errors_with_no_prior_generation() {
+        let mut game =
+            GameOfLife::from_plaintext("...\n.*.\n...\n", None, Rules::conway()).unwrap();
+        assert!(game.undo().is_err());
+    }
+
+    #[test]
+    fn reset_restores_the_initial_seed_and_clears_history() {
+        let mut game =
+            GameOfLife::from_plaintext("...\n.*.\n...\n", None, Rules::conway()).unwrap();
+        game.step();
+        game.step();
+
+        game.reset();
+        assert_eq!(game.generation(), 0);
+        assert!(game.get_cell(4).unwrap().alive);
+        assert!(game.undo().is_err()); // history was cleared by reset
+    }
+
+    #[test]
+    fn reset_clears_inner_grids_spawned_by_fractal_mode() {
+        // A fully packed 3x3 grid: the center cell has 8 alive neighbors,
+        // well past the default spawn_threshold of 6, so stepping once
+        // spawns a nested board on it.
+        let mut game = GameOfLife::from_plaintext("***\n***\n***\n", None, Rules::conway())
+            .unwrap()
+            .with_fractal(FractalConfig::default());
+        game.step();
+        assert!(game.get_cell(4).unwrap().inner.is_some());
+
+        game.reset();
+        assert!(game.get_cell(4).unwrap().inner.is_none());
+    }
+
+    #[test]
+    fn fractal_mode_spawns_then_despawns_as_the_board_thins_out() {
+        // A fully packed 3x3 grid dies off from the inside out: the center
+        // starts with 8 alive neighbors (spawns), keeps 4 after gen 1 (the
+        // surviving corners, still above despawn_threshold), then drops to 0
+        // once the corners die off from isolation in gen 2 (despawns).
+        let mut game = GameOfLife::from_plaintext("***\n***\n***\n", None, Rules::conway())
+            .unwrap()
+            .with_fractal(FractalConfig::default());
+
+        game.step(); // gen 0 -> 1: 8 neighbors, spawns
+        assert!(game.get_cell(4).unwrap().inner.is_some());
+
+        game.step(); // gen 1 -> 2: 4 neighbors, stays spawned
+        assert!(game.get_cell(4).unwrap().inner.is_some());
+
+        game.step(); // gen 2 -> 3: 0 neighbors, despawns
+        assert!(game.get_cell(4).unwrap().inner.is_none());
+    }
 }
@@ -1,8 +1,7 @@
-mod game_of_life;
-use game_of_life::GameOfLife;
+use conway::game_of_life::{GameOfLife, Rules};
 
 fn main() {
-    let mut game = GameOfLife::from_random_grid(10, 10, 0.3);
+    let mut game = GameOfLife::from_random_grid(10, 10, 0.3, Rules::conway());
 
     for generation in 0..101 {
         println!("Generation {}", generation);